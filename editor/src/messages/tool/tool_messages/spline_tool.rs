@@ -24,6 +24,18 @@ pub struct SplineOptions {
 	line_weight: f64,
 	fill: ToolColorOptions,
 	stroke: ToolColorOptions,
+	/// Draw a continuous freehand stroke instead of placing points one click at a time.
+	freehand_mode: bool,
+	/// How far the cursor must pull away from the stabilized brush before it follows, in viewport pixels.
+	stabilizer_distance: f64,
+	/// Vary the stroke weight per point with pointer speed instead of staying flat at `line_weight`.
+	speed_responsive: bool,
+	/// The thinnest the stroke is allowed to get.
+	min_weight: f64,
+	/// The thickest the stroke is allowed to get.
+	max_weight: f64,
+	/// The mirror/radial symmetry applied to every point placed while drawing.
+	symmetry_mode: SplineSymmetryMode,
 }
 
 impl Default for SplineOptions {
@@ -32,6 +44,35 @@ impl Default for SplineOptions {
 			line_weight: DEFAULT_STROKE_WIDTH,
 			fill: ToolColorOptions::new_none(),
 			stroke: ToolColorOptions::new_primary(),
+			freehand_mode: false,
+			stabilizer_distance: 0.,
+			speed_responsive: false,
+			min_weight: DEFAULT_STROKE_WIDTH / 2.,
+			max_weight: DEFAULT_STROKE_WIDTH * 2.,
+			symmetry_mode: SplineSymmetryMode::Off,
+		}
+	}
+}
+
+/// The mirror/radial symmetry applied to points placed by the Spline Tool.
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub enum SplineSymmetryMode {
+	#[default]
+	Off,
+	Horizontal,
+	Vertical,
+	Both,
+	Radial(u32),
+}
+
+impl SplineSymmetryMode {
+	/// How many mirror copies this mode produces, excluding the original point.
+	fn copy_count(self) -> usize {
+		match self {
+			Self::Off => 0,
+			Self::Horizontal | Self::Vertical => 1,
+			Self::Both => 3,
+			Self::Radial(petals) => petals.saturating_sub(1) as usize,
 		}
 	}
 }
@@ -47,8 +88,10 @@ pub enum SplineToolMessage {
 
 	// Tool-specific messages
 	Confirm,
+	DeleteSelectedPoints,
 	DragStart,
 	DragStop,
+	NudgeSelectedPoints(DVec2),
 	PointerMove,
 	PointerOutsideViewport,
 	Undo,
@@ -66,7 +109,13 @@ enum SplineToolFsmState {
 pub enum SplineOptionsUpdate {
 	FillColor(Option<Color>),
 	FillColorType(ToolColorType),
+	FreehandMode(bool),
 	LineWeight(f64),
+	MaxWeight(f64),
+	MinWeight(f64),
+	SpeedResponsive(bool),
+	StabilizerDistance(f64),
+	SymmetryMode(SplineSymmetryMode),
 	StrokeColor(Option<Color>),
 	StrokeColorType(ToolColorType),
 	WorkingColors(Option<Color>, Option<Color>),
@@ -94,6 +143,85 @@ fn create_weight_widget(line_weight: f64) -> WidgetHolder {
 		.widget_holder()
 }
 
+fn create_freehand_widgets(freehand_mode: bool, stabilizer_distance: f64) -> Vec<WidgetHolder> {
+	vec![
+		CheckboxInput::new(freehand_mode)
+			.icon("Edit")
+			.tooltip("Freehand: draw a continuous stroke that is stabilized and reduced to spline points")
+			.on_update(|checkbox_input: &CheckboxInput| SplineToolMessage::UpdateOptions(SplineOptionsUpdate::FreehandMode(checkbox_input.checked)).into())
+			.widget_holder(),
+		Separator::new(SeparatorType::Related).widget_holder(),
+		NumberInput::new(Some(stabilizer_distance))
+			.unit(" px")
+			.label("Stabilizer")
+			.min(0.)
+			.disabled(!freehand_mode)
+			.on_update(|number_input: &NumberInput| SplineToolMessage::UpdateOptions(SplineOptionsUpdate::StabilizerDistance(number_input.value.unwrap())).into())
+			.widget_holder(),
+	]
+}
+
+fn create_speed_widgets(speed_responsive: bool, min_weight: f64, max_weight: f64) -> Vec<WidgetHolder> {
+	vec![
+		CheckboxInput::new(speed_responsive)
+			.icon("Brush")
+			.tooltip("Speed: vary the stroke weight per point with pointer speed")
+			.on_update(|checkbox_input: &CheckboxInput| SplineToolMessage::UpdateOptions(SplineOptionsUpdate::SpeedResponsive(checkbox_input.checked)).into())
+			.widget_holder(),
+		Separator::new(SeparatorType::Related).widget_holder(),
+		NumberInput::new(Some(min_weight))
+			.unit(" px")
+			.label("Min")
+			.min(0.)
+			.disabled(!speed_responsive)
+			.on_update(|number_input: &NumberInput| SplineToolMessage::UpdateOptions(SplineOptionsUpdate::MinWeight(number_input.value.unwrap())).into())
+			.widget_holder(),
+		Separator::new(SeparatorType::Related).widget_holder(),
+		NumberInput::new(Some(max_weight))
+			.unit(" px")
+			.label("Max")
+			.min(0.)
+			.disabled(!speed_responsive)
+			.on_update(|number_input: &NumberInput| SplineToolMessage::UpdateOptions(SplineOptionsUpdate::MaxWeight(number_input.value.unwrap())).into())
+			.widget_holder(),
+	]
+}
+
+fn create_symmetry_widgets(symmetry_mode: SplineSymmetryMode) -> Vec<WidgetHolder> {
+	let entries = [
+		("Off", SplineSymmetryMode::Off),
+		("Horizontal", SplineSymmetryMode::Horizontal),
+		("Vertical", SplineSymmetryMode::Vertical),
+		("Both", SplineSymmetryMode::Both),
+		("Radial", SplineSymmetryMode::Radial(6)),
+	]
+	.map(|(label, mode)| RadioEntryData::new(label).label(label).on_update(move |_| SplineToolMessage::UpdateOptions(SplineOptionsUpdate::SymmetryMode(mode)).into()));
+
+	let selected_index = match symmetry_mode {
+		SplineSymmetryMode::Off => 0,
+		SplineSymmetryMode::Horizontal => 1,
+		SplineSymmetryMode::Vertical => 2,
+		SplineSymmetryMode::Both => 3,
+		SplineSymmetryMode::Radial(_) => 4,
+	};
+
+	let mut widgets = vec![RadioInput::new(entries.to_vec()).selected_index(Some(selected_index)).widget_holder()];
+
+	if let SplineSymmetryMode::Radial(petals) = symmetry_mode {
+		widgets.push(Separator::new(SeparatorType::Related).widget_holder());
+		widgets.push(
+			NumberInput::new(Some(petals as f64))
+				.label("Petals")
+				.int()
+				.min(2.)
+				.on_update(|number_input: &NumberInput| SplineToolMessage::UpdateOptions(SplineOptionsUpdate::SymmetryMode(SplineSymmetryMode::Radial(number_input.value.unwrap() as u32))).into())
+				.widget_holder(),
+		);
+	}
+
+	widgets
+}
+
 impl LayoutHolder for SplineTool {
 	fn layout(&self) -> Layout {
 		let mut widgets = self.options.fill.create_widgets(
@@ -116,6 +244,15 @@ impl LayoutHolder for SplineTool {
 		widgets.push(Separator::new(SeparatorType::Unrelated).widget_holder());
 		widgets.push(create_weight_widget(self.options.line_weight));
 
+		widgets.push(Separator::new(SeparatorType::Unrelated).widget_holder());
+		widgets.append(&mut create_freehand_widgets(self.options.freehand_mode, self.options.stabilizer_distance));
+
+		widgets.push(Separator::new(SeparatorType::Unrelated).widget_holder());
+		widgets.append(&mut create_speed_widgets(self.options.speed_responsive, self.options.min_weight, self.options.max_weight));
+
+		widgets.push(Separator::new(SeparatorType::Unrelated).widget_holder());
+		widgets.append(&mut create_symmetry_widgets(self.options.symmetry_mode));
+
 		Layout::WidgetLayout(WidgetLayout::new(vec![LayoutGroup::Row { widgets }]))
 	}
 }
@@ -128,6 +265,12 @@ impl<'a> MessageHandler<ToolMessage, &mut ToolActionHandlerData<'a>> for SplineT
 		};
 		match action {
 			SplineOptionsUpdate::LineWeight(line_weight) => self.options.line_weight = line_weight,
+			SplineOptionsUpdate::FreehandMode(freehand_mode) => self.options.freehand_mode = freehand_mode,
+			SplineOptionsUpdate::StabilizerDistance(stabilizer_distance) => self.options.stabilizer_distance = stabilizer_distance.max(0.),
+			SplineOptionsUpdate::SpeedResponsive(speed_responsive) => self.options.speed_responsive = speed_responsive,
+			SplineOptionsUpdate::MinWeight(min_weight) => self.options.min_weight = min_weight.max(0.),
+			SplineOptionsUpdate::MaxWeight(max_weight) => self.options.max_weight = max_weight.max(0.),
+			SplineOptionsUpdate::SymmetryMode(symmetry_mode) => self.options.symmetry_mode = symmetry_mode,
 			SplineOptionsUpdate::FillColor(color) => {
 				self.options.fill.custom_color = color;
 				self.options.fill.color_type = ToolColorType::Custom;
@@ -159,10 +302,13 @@ impl<'a> MessageHandler<ToolMessage, &mut ToolActionHandlerData<'a>> for SplineT
 				Abort,
 			),
 			SplineToolFsmState::Drawing => actions!(SplineToolMessageDiscriminant;
+				DragStart,
 				DragStop,
 				PointerMove,
 				Confirm,
 				Abort,
+				DeleteSelectedPoints,
+				NudgeSelectedPoints,
 			),
 		}
 	}
@@ -182,10 +328,30 @@ impl ToolTransition for SplineTool {
 
 #[derive(Clone, Debug, Default)]
 struct SplineToolData {
-	/// Points that are inserted.
-	points: Vec<(PointId, DVec2)>,
+	/// Points that are inserted, alongside the stroke weight sampled when each was placed and the outgoing handle set
+	/// by a later handle drag (`None` until then).
+	points: Vec<(PointId, DVec2, f64, Option<DVec2>)>,
+	/// Committed segments in placement order, alongside their current handle positions.
+	segments: Vec<(SegmentId, [Option<DVec2>; 2])>,
+	/// The most recently placed point, latched on `DragStart` as a candidate for a pen-tool handle drag.
+	handle_drag_candidate: Option<(PointId, DVec2)>,
+	/// The cursor position, in layer space, where the current `DragStart` occurred.
+	handle_drag_start: Option<DVec2>,
+	/// The point whose handles are currently being shaped by an active drag.
+	editing_handle: Option<PointId>,
+	/// The corner of the rubber-band selection rectangle where the modifier-held drag began.
+	box_select_origin: Option<DVec2>,
+	/// The opposite corner of the in-progress rubber-band selection rectangle.
+	box_select_current: Option<DVec2>,
+	/// Points selected by the rubber-band box, eligible for deletion or arrow-key nudging. Mirror copies of a point
+	/// are never added here, so deleting or nudging a selected point leaves its mirrors behind unchanged.
+	selected_points: Vec<PointId>,
 	/// Point to be inserted.
 	next_point: DVec2,
+	/// Weight sampled for `next_point`.
+	next_point_weight: f64,
+	/// The position of the previous `PointerMove` sample, used to estimate speed.
+	last_move_position: Option<DVec2>,
 	/// Point that was inserted temporarily to show preview.
 	preview_point: Option<PointId>,
 	/// Segment that was inserted temporarily to show preview.
@@ -194,6 +360,24 @@ struct SplineToolData {
 	layer: Option<LayerNodeIdentifier>,
 	snap_manager: SnapManager,
 	auto_panning: AutoPanning,
+	/// Whether the current drag is being captured as a freehand stroke, latched at `DragStart`.
+	freehand_drawing: bool,
+	/// The stabilized "pulled string" brush position that trails the raw cursor. Kept in viewport pixels (like
+	/// `stabilizer_distance`) rather than layer space, so the stabilizer strength doesn't scale with document zoom.
+	stabilized_brush_position: Option<DVec2>,
+	/// Stabilized samples collected during the current freehand stroke, thinned into spline points once it ends.
+	freehand_samples: Vec<(DVec2, f64)>,
+	/// The symmetry mode latched at `DragStart`.
+	symmetry_mode: SplineSymmetryMode,
+	/// The axis/center of symmetry, in layer space, fixed to the first committed point.
+	symmetry_origin: Option<DVec2>,
+	/// The most recently placed point of each mirror copy, used to connect new mirrored segments. Not reachable by
+	/// box-select/delete/nudge, unlike `points`.
+	mirror_last_point: Vec<Option<PointId>>,
+	/// Mirror points inserted temporarily to show the symmetric preview.
+	preview_mirror_points: Vec<PointId>,
+	/// Mirror segments inserted temporarily to show the symmetric preview.
+	preview_mirror_segments: Vec<SegmentId>,
 }
 
 impl Fsm for SplineToolFsmState {
@@ -215,6 +399,17 @@ impl Fsm for SplineToolFsmState {
 			(_, SplineToolMessage::Overlays(mut overlay_context)) => {
 				path_endpoint_overlays(document, shape_editor, &mut overlay_context);
 
+				if let (Some(layer), Some(origin)) = (tool_data.layer, tool_data.symmetry_origin) {
+					draw_symmetry_overlays(document, layer, origin, tool_data.symmetry_mode, &mut overlay_context);
+				}
+				if let Some(layer) = tool_data.layer {
+					draw_handle_overlays(document, layer, &tool_data.points, &mut overlay_context);
+					draw_selection_overlays(document, layer, &tool_data.points, &tool_data.selected_points, &mut overlay_context);
+				}
+				if let (Some(layer), Some(origin), Some(current)) = (tool_data.layer, tool_data.box_select_origin, tool_data.box_select_current) {
+					draw_box_select_overlay(document, layer, origin, current, &mut overlay_context);
+				}
+
 				self
 			}
 			(SplineToolFsmState::Ready, SplineToolMessage::DragStart) => {
@@ -235,17 +430,89 @@ impl Fsm for SplineToolFsmState {
 				tool_options.fill.apply_fill(layer, responses);
 				tool_options.stroke.apply_stroke(tool_data.weight, layer, responses);
 				tool_data.layer = Some(layer);
+				tool_data.segments.clear();
+				tool_data.handle_drag_candidate = None;
+				tool_data.handle_drag_start = None;
+				tool_data.editing_handle = None;
+				tool_data.box_select_origin = None;
+				tool_data.box_select_current = None;
+				tool_data.selected_points.clear();
+				tool_data.freehand_drawing = tool_options.freehand_mode;
+				tool_data.stabilized_brush_position = None;
+				tool_data.freehand_samples.clear();
+				tool_data.last_move_position = None;
+				tool_data.symmetry_mode = tool_options.symmetry_mode;
+				tool_data.symmetry_origin = None;
+				tool_data.mirror_last_point = vec![None; tool_options.symmetry_mode.copy_count()];
+				tool_data.preview_mirror_points.clear();
+				tool_data.preview_mirror_segments.clear();
 
 				responses.add(Message::StartBuffer);
 
 				SplineToolFsmState::Drawing
 			}
+			(SplineToolFsmState::Drawing, SplineToolMessage::DragStart) => {
+				let Some(layer) = tool_data.layer else {
+					return SplineToolFsmState::Drawing;
+				};
+				let transform = document.metadata().transform_to_viewport(layer);
+				let pos = transform.inverse().transform_point2(input.mouse.position);
+
+				// Holding the selection modifier starts a rubber-band box instead of placing a point or dragging a
+				// handle, so a misplaced point can be fixed without aborting the whole stroke.
+				if input.keyboard.key(Key::Control) {
+					tool_data.box_select_origin = Some(pos);
+					tool_data.box_select_current = Some(pos);
+
+					return SplineToolFsmState::Drawing;
+				}
+
+				// Pressing on top of the most recently placed point starts a pen-tool handle drag instead of placing
+				// a new point; otherwise this is an ordinary click-drag and falls through to `PointerMove`/`DragStop`.
+				tool_data.handle_drag_candidate = tool_data.points.last().filter(|(_, point_pos, ..)| point_pos.distance(pos) <= DRAG_THRESHOLD).map(|&(id, point_pos, ..)| (id, point_pos));
+				tool_data.handle_drag_start = tool_data.handle_drag_candidate.is_some().then_some(pos);
+
+				SplineToolFsmState::Drawing
+			}
 			(SplineToolFsmState::Drawing, SplineToolMessage::DragStop) => {
 				responses.add(DocumentMessage::EndTransaction);
 
 				let Some(layer) = tool_data.layer else {
 					return SplineToolFsmState::Ready;
 				};
+
+				if let (Some(origin), Some(current)) = (tool_data.box_select_origin.take(), tool_data.box_select_current.take()) {
+					let min = origin.min(current);
+					let max = origin.max(current);
+					tool_data.selected_points = tool_data.points.iter().filter(|(_, pos, ..)| pos.cmpge(min).all() && pos.cmple(max).all()).map(|&(id, ..)| id).collect();
+
+					return SplineToolFsmState::Drawing;
+				}
+
+				if tool_data.freehand_drawing {
+					tool_data.freehand_drawing = false;
+					delete_preview(tool_data, responses);
+
+					for (point, weight) in thin_freehand_samples(&tool_data.freehand_samples) {
+						tool_data.next_point = point;
+						tool_data.next_point_weight = weight;
+						update_spline(tool_data, false, responses);
+					}
+
+					tool_data.freehand_samples.clear();
+					tool_data.stabilized_brush_position = None;
+					tool_data.last_move_position = None;
+
+					return SplineToolFsmState::Drawing;
+				}
+
+				tool_data.handle_drag_candidate = None;
+				tool_data.handle_drag_start = None;
+				if tool_data.editing_handle.take().is_some() {
+					// This drag shaped the previous point's handles rather than placing a new point.
+					return SplineToolFsmState::Drawing;
+				}
+
 				let snapped_position = input.mouse.position;
 				let transform = document.metadata().transform_to_viewport(layer);
 				let pos = transform.inverse().transform_point2(snapped_position);
@@ -253,6 +520,8 @@ impl Fsm for SplineToolFsmState {
 				if tool_data.points.last().map_or(true, |last_pos| last_pos.1.distance(pos) > DRAG_THRESHOLD) {
 					tool_data.next_point = pos;
 				}
+				tool_data.next_point_weight = sample_point_weight(tool_options, tool_data.last_move_position, tool_data.next_point);
+				tool_data.last_move_position = Some(tool_data.next_point);
 
 				update_spline(tool_data, false, responses);
 
@@ -265,7 +534,47 @@ impl Fsm for SplineToolFsmState {
 				let snapped_position = input.mouse.position; // tool_data.snap_manager.snap_position(responses, document, input.mouse.position);
 				let transform = document.metadata().transform_to_viewport(layer);
 				let pos = transform.inverse().transform_point2(snapped_position);
-				tool_data.next_point = pos;
+
+				if tool_data.box_select_origin.is_some() {
+					tool_data.box_select_current = Some(pos);
+
+					return SplineToolFsmState::Drawing;
+				}
+
+				if let Some((candidate_id, candidate_pos)) = tool_data.handle_drag_candidate {
+					let drag_start = tool_data.handle_drag_start.unwrap_or(candidate_pos);
+					if tool_data.editing_handle.is_none() && drag_start.distance(pos) > DRAG_THRESHOLD {
+						tool_data.editing_handle = Some(candidate_id);
+					}
+
+					if let Some(editing_point_id) = tool_data.editing_handle {
+						let break_symmetry = input.keyboard.key(Key::Alt);
+						apply_point_handle_drag(tool_data, editing_point_id, candidate_pos, pos, break_symmetry, layer, responses);
+
+						// Auto-panning
+						let messages = [SplineToolMessage::PointerOutsideViewport.into(), SplineToolMessage::PointerMove.into()];
+						tool_data.auto_panning.setup_by_mouse_position(input, &messages, responses);
+
+						return SplineToolFsmState::Drawing;
+					}
+				}
+
+				let weight = sample_point_weight(tool_options, tool_data.last_move_position, pos);
+
+				if tool_data.freehand_drawing {
+					// Pull the brush in viewport space so `stabilizer_distance` (viewport pixels) stays constant
+					// regardless of document zoom, then convert the result back to layer space for the stroke.
+					let brush_position_viewport = tool_data.stabilized_brush_position.unwrap_or(snapped_position);
+					let stabilized_viewport = pull_stabilized_brush(brush_position_viewport, snapped_position, tool_options.stabilizer_distance);
+					tool_data.stabilized_brush_position = Some(stabilized_viewport);
+					let stabilized = transform.inverse().transform_point2(stabilized_viewport);
+					tool_data.freehand_samples.push((stabilized, weight));
+					tool_data.next_point = stabilized;
+				} else {
+					tool_data.next_point = pos;
+				}
+				tool_data.next_point_weight = weight;
+				tool_data.last_move_position = Some(pos);
 
 				update_spline(tool_data, true, responses);
 
@@ -300,10 +609,52 @@ impl Fsm for SplineToolFsmState {
 				tool_data.preview_point = None;
 				tool_data.preview_segment = None;
 				tool_data.points.clear();
+				tool_data.segments.clear();
+				tool_data.handle_drag_candidate = None;
+				tool_data.handle_drag_start = None;
+				tool_data.editing_handle = None;
+				tool_data.box_select_origin = None;
+				tool_data.box_select_current = None;
+				tool_data.selected_points.clear();
 				tool_data.snap_manager.cleanup(responses);
+				tool_data.freehand_drawing = false;
+				tool_data.stabilized_brush_position = None;
+				tool_data.freehand_samples.clear();
+				tool_data.last_move_position = None;
+				tool_data.symmetry_origin = None;
+				tool_data.mirror_last_point.clear();
+				tool_data.preview_mirror_points.clear();
+				tool_data.preview_mirror_segments.clear();
 
 				SplineToolFsmState::Ready
 			}
+			(SplineToolFsmState::Drawing, SplineToolMessage::DeleteSelectedPoints) => {
+				let Some(layer) = tool_data.layer else {
+					return SplineToolFsmState::Ready;
+				};
+
+				delete_preview(tool_data, responses);
+
+				for point_id in std::mem::take(&mut tool_data.selected_points) {
+					remove_spline_point(tool_data, layer, point_id, responses);
+				}
+
+				SplineToolFsmState::Drawing
+			}
+			(SplineToolFsmState::Drawing, SplineToolMessage::NudgeSelectedPoints(delta)) => {
+				let Some(layer) = tool_data.layer else {
+					return SplineToolFsmState::Ready;
+				};
+
+				for point in tool_data.points.iter_mut().filter(|(id, ..)| tool_data.selected_points.contains(id)) {
+					point.1 += delta;
+
+					let modification_type = VectorModificationType::ApplyPointDelta { point: point.0, delta };
+					responses.add(GraphOperationMessage::Vector { layer, modification_type });
+				}
+
+				SplineToolFsmState::Drawing
+			}
 			(_, SplineToolMessage::WorkingColorChanged) => {
 				responses.add(SplineToolMessage::UpdateOptions(SplineOptionsUpdate::WorkingColors(
 					Some(global_tool_data.primary_color),
@@ -339,6 +690,7 @@ fn update_spline(tool_data: &mut SplineToolData, show_preview: bool, responses:
 	let Some(layer) = tool_data.layer else { return };
 
 	let next_point_pos = tool_data.next_point;
+	let next_point_weight = tool_data.next_point_weight;
 	let next_point_id = PointId::generate();
 	let modification_type = VectorModificationType::InsertPoint {
 		id: next_point_id,
@@ -346,24 +698,231 @@ fn update_spline(tool_data: &mut SplineToolData, show_preview: bool, responses:
 	};
 	responses.add(GraphOperationMessage::Vector { layer, modification_type });
 
-	if let Some((last_point_id, _)) = tool_data.points.last() {
-		let points = [*last_point_id, next_point_id];
+	let modification_type = VectorModificationType::SetPointWeight {
+		id: next_point_id,
+		weight: next_point_weight,
+	};
+	responses.add(GraphOperationMessage::Vector { layer, modification_type });
+
+	if let Some(&(last_point_id, _, _, last_point_out_handle)) = tool_data.points.last() {
+		let points = [last_point_id, next_point_id];
 		let id = SegmentId::generate();
-		let modification_type = VectorModificationType::InsertSegment { id, points, handles: [None, None] };
+		let handles = [last_point_out_handle, None];
+		let modification_type = VectorModificationType::InsertSegment { id, points, handles };
 		responses.add(GraphOperationMessage::Vector { layer, modification_type });
 
 		if show_preview {
 			tool_data.preview_segment = Some(id);
+		} else {
+			tool_data.segments.push((id, handles));
 		}
 	}
 
 	if show_preview {
 		tool_data.preview_point = Some(next_point_id);
 	} else {
-		tool_data.points.push((next_point_id, next_point_pos));
+		tool_data.points.push((next_point_id, next_point_pos, next_point_weight, None));
+	}
+
+	// The first committed point defines the symmetry axis/center rather than being mirrored against it.
+	if tool_data.symmetry_mode != SplineSymmetryMode::Off && !show_preview && tool_data.symmetry_origin.is_none() {
+		tool_data.symmetry_origin = Some(next_point_pos);
+	} else if let Some(origin) = tool_data.symmetry_origin {
+		for (copy_index, mirrored_pos) in mirrored_positions(tool_data.symmetry_mode, origin, next_point_pos).into_iter().enumerate() {
+			let mirror_id = PointId::generate();
+			let modification_type = VectorModificationType::InsertPoint { id: mirror_id, position: mirrored_pos };
+			responses.add(GraphOperationMessage::Vector { layer, modification_type });
+
+			let modification_type = VectorModificationType::SetPointWeight { id: mirror_id, weight: next_point_weight };
+			responses.add(GraphOperationMessage::Vector { layer, modification_type });
+
+			if let Some(last_mirror_id) = tool_data.mirror_last_point.get(copy_index).copied().flatten() {
+				let points = [last_mirror_id, mirror_id];
+				let id = SegmentId::generate();
+				let modification_type = VectorModificationType::InsertSegment { id, points, handles: [None, None] };
+				responses.add(GraphOperationMessage::Vector { layer, modification_type });
+
+				if show_preview {
+					tool_data.preview_mirror_segments.push(id);
+				}
+			}
+
+			if show_preview {
+				tool_data.preview_mirror_points.push(mirror_id);
+			} else if let Some(slot) = tool_data.mirror_last_point.get_mut(copy_index) {
+				*slot = Some(mirror_id);
+			}
+		}
 	}
 }
 
+/// Shapes `point_id`'s tangents from a pen-tool-style drag, mirroring the incoming handle unless `break_symmetry`
+/// (held with Alt) is set, in which case the incoming handle keeps its current position.
+fn apply_point_handle_drag(tool_data: &mut SplineToolData, point_id: PointId, point_pos: DVec2, cursor_pos: DVec2, break_symmetry: bool, layer: LayerNodeIdentifier, responses: &mut VecDeque<Message>) {
+	let Some(point_index) = tool_data.points.iter().position(|&(id, ..)| id == point_id) else {
+		return;
+	};
+
+	if let Some(point) = tool_data.points.get_mut(point_index) {
+		point.3 = Some(cursor_pos);
+	}
+
+	if point_index == 0 {
+		return;
+	}
+	let Some((segment_id, handles)) = tool_data.segments.get_mut(point_index - 1) else {
+		return;
+	};
+
+	if !break_symmetry {
+		handles[1] = Some(2. * point_pos - cursor_pos);
+	}
+	let modification_type = VectorModificationType::SetSegmentHandles { segment: *segment_id, handles: *handles };
+	responses.add(GraphOperationMessage::Vector { layer, modification_type });
+}
+
+/// Computes the mirror copies of `point` for the given symmetry mode, about `origin`.
+fn mirrored_positions(mode: SplineSymmetryMode, origin: DVec2, point: DVec2) -> Vec<DVec2> {
+	let reflect_horizontal = |point: DVec2| DVec2::new(point.x, 2. * origin.y - point.y);
+	let reflect_vertical = |point: DVec2| DVec2::new(2. * origin.x - point.x, point.y);
+
+	match mode {
+		SplineSymmetryMode::Off => Vec::new(),
+		SplineSymmetryMode::Horizontal => vec![reflect_horizontal(point)],
+		SplineSymmetryMode::Vertical => vec![reflect_vertical(point)],
+		SplineSymmetryMode::Both => vec![reflect_horizontal(point), reflect_vertical(point), reflect_vertical(reflect_horizontal(point))],
+		SplineSymmetryMode::Radial(petals) => {
+			let petals = petals.max(1);
+			let relative = point - origin;
+			(1..petals).map(|k| origin + relative.rotate(DVec2::from_angle(k as f64 * std::f64::consts::TAU / petals as f64))).collect()
+		}
+	}
+}
+
+/// Draws the symmetry axes (or radial spokes) through `origin`.
+fn draw_symmetry_overlays(document: &DocumentMessageHandler, layer: LayerNodeIdentifier, origin: DVec2, mode: SplineSymmetryMode, overlay_context: &mut OverlayContext) {
+	if mode == SplineSymmetryMode::Off {
+		return;
+	}
+
+	const AXIS_LENGTH: f64 = 4096.;
+	let transform = document.metadata().transform_to_viewport(layer);
+	let origin_viewport = transform.transform_point2(origin);
+
+	let mut draw_axis = |direction: DVec2| {
+		let direction_viewport = transform.transform_vector2(direction).try_normalize().unwrap_or(DVec2::X);
+		overlay_context.line(origin_viewport - direction_viewport * AXIS_LENGTH, origin_viewport + direction_viewport * AXIS_LENGTH, None, None);
+	};
+
+	match mode {
+		SplineSymmetryMode::Off => {}
+		SplineSymmetryMode::Horizontal => draw_axis(DVec2::X),
+		SplineSymmetryMode::Vertical => draw_axis(DVec2::Y),
+		SplineSymmetryMode::Both => {
+			draw_axis(DVec2::X);
+			draw_axis(DVec2::Y);
+		}
+		SplineSymmetryMode::Radial(petals) => {
+			let petals = petals.max(1);
+			for k in 0..petals {
+				draw_axis(DVec2::from_angle(k as f64 * std::f64::consts::TAU / petals as f64));
+			}
+		}
+	}
+}
+
+/// Draws each placed point's outgoing handle and its mirrored incoming tangent.
+fn draw_handle_overlays(document: &DocumentMessageHandler, layer: LayerNodeIdentifier, points: &[(PointId, DVec2, f64, Option<DVec2>)], overlay_context: &mut OverlayContext) {
+	let transform = document.metadata().transform_to_viewport(layer);
+
+	for &(_, point_pos, _, out_handle) in points {
+		let Some(out_handle) = out_handle else { continue };
+
+		let point_viewport = transform.transform_point2(point_pos);
+		let out_handle_viewport = transform.transform_point2(out_handle);
+		let in_handle_viewport = transform.transform_point2(2. * point_pos - out_handle);
+
+		overlay_context.line(point_viewport, out_handle_viewport, None, None);
+		overlay_context.manipulator_handle(out_handle_viewport, false, None);
+
+		overlay_context.line(point_viewport, in_handle_viewport, None, None);
+		overlay_context.manipulator_handle(in_handle_viewport, false, None);
+	}
+}
+
+/// Samples the stroke weight for a point: flat `line_weight`, or `line_weight` shifted by the speed factor (by up to half
+/// of `max_weight - min_weight` either way) and clamped to `min_weight..=max_weight`.
+fn sample_point_weight(tool_options: &SplineOptions, previous_position: Option<DVec2>, current_position: DVec2) -> f64 {
+	if !tool_options.speed_responsive {
+		return tool_options.line_weight;
+	}
+
+	let factor = speed_factor(previous_position, current_position);
+	let adjustment = (factor - 0.5) * (tool_options.max_weight - tool_options.min_weight);
+	(tool_options.line_weight + adjustment).clamp(tool_options.min_weight, tool_options.max_weight)
+}
+
+/// Returns a value in roughly `0..=1`, derived from how far the cursor moved since the last sample.
+fn speed_factor(previous_position: Option<DVec2>, current_position: DVec2) -> f64 {
+	const MAX_EXPECTED_SPEED: f64 = 40.;
+
+	let speed = previous_position.map_or(0., |previous_position| previous_position.distance(current_position));
+	(1. - (speed / MAX_EXPECTED_SPEED).min(1.)).max(0.)
+}
+
+/// Advances the "pulled string" brush toward the cursor once it strays past `stabilizer_distance`, closing the gap
+/// back to exactly that distance.
+fn pull_stabilized_brush(brush_position: DVec2, raw_cursor: DVec2, stabilizer_distance: f64) -> DVec2 {
+	if stabilizer_distance <= 0. {
+		return raw_cursor;
+	}
+
+	let offset = raw_cursor - brush_position;
+	let distance = offset.length();
+	if distance <= stabilizer_distance {
+		return brush_position;
+	}
+
+	brush_position + offset.normalize() * (distance - stabilizer_distance)
+}
+
+/// Thins a freehand stroke's stabilized samples to a clean set of spline control points, dropping points within
+/// `DRAG_THRESHOLD` of the last kept one or nearly collinear with their neighbors.
+fn thin_freehand_samples(samples: &[(DVec2, f64)]) -> Vec<(DVec2, f64)> {
+	const ANGLE_EPSILON: f64 = 0.02;
+
+	let Some((&first, rest)) = samples.split_first() else {
+		return Vec::new();
+	};
+	let Some((&last, middle)) = rest.split_last() else {
+		return vec![first];
+	};
+
+	let mut kept = vec![first];
+	for &candidate in middle {
+		let (previous, _) = *kept.last().unwrap();
+		if previous.distance(candidate.0) < DRAG_THRESHOLD {
+			continue;
+		}
+
+		if kept.len() >= 2 {
+			let incoming = (previous - kept[kept.len() - 2].0).normalize_or_zero();
+			let outgoing = (candidate.0 - previous).normalize_or_zero();
+			if incoming.angle_between(outgoing).abs() < ANGLE_EPSILON {
+				continue;
+			}
+		}
+
+		kept.push(candidate);
+	}
+
+	if kept.last().map_or(true, |&(point, _)| point.distance(last.0) >= DRAG_THRESHOLD) {
+		kept.push(last);
+	}
+
+	kept
+}
+
 fn delete_preview(tool_data: &mut SplineToolData, responses: &mut VecDeque<Message>) {
 	let Some(layer) = tool_data.layer else { return };
 
@@ -375,7 +934,202 @@ fn delete_preview(tool_data: &mut SplineToolData, responses: &mut VecDeque<Messa
 		let modification_type = VectorModificationType::RemoveSegment { id };
 		responses.add(GraphOperationMessage::Vector { layer, modification_type });
 	}
+	for id in tool_data.preview_mirror_points.drain(..) {
+		let modification_type = VectorModificationType::RemovePoint { id };
+		responses.add(GraphOperationMessage::Vector { layer, modification_type });
+	}
+	for id in tool_data.preview_mirror_segments.drain(..) {
+		let modification_type = VectorModificationType::RemoveSegment { id };
+		responses.add(GraphOperationMessage::Vector { layer, modification_type });
+	}
 
 	tool_data.preview_point = None;
 	tool_data.preview_segment = None;
 }
+
+/// Removes a placed point and the segment(s) touching it, rejoining its former neighbors with a fresh segment so the
+/// spline stays connected instead of splitting into two strokes.
+fn remove_spline_point(tool_data: &mut SplineToolData, layer: LayerNodeIdentifier, point_id: PointId, responses: &mut VecDeque<Message>) {
+	let Some(point_index) = tool_data.points.iter().position(|&(id, ..)| id == point_id) else {
+		return;
+	};
+
+	let previous_segment = point_index.checked_sub(1).and_then(|index| tool_data.segments.get(index).copied());
+	let next_segment = tool_data.segments.get(point_index).copied();
+
+	if let Some((segment_id, _)) = previous_segment {
+		responses.add(GraphOperationMessage::Vector {
+			layer,
+			modification_type: VectorModificationType::RemoveSegment { id: segment_id },
+		});
+	}
+	if let Some((segment_id, _)) = next_segment {
+		responses.add(GraphOperationMessage::Vector {
+			layer,
+			modification_type: VectorModificationType::RemoveSegment { id: segment_id },
+		});
+	}
+	responses.add(GraphOperationMessage::Vector {
+		layer,
+		modification_type: VectorModificationType::RemovePoint { id: point_id },
+	});
+
+	let previous_point = point_index.checked_sub(1).and_then(|index| tool_data.points.get(index));
+	let next_point = tool_data.points.get(point_index + 1);
+	let rejoined = if let (Some(&(previous_id, ..)), Some(&(next_id, ..))) = (previous_point, next_point) {
+		let segment_id = SegmentId::generate();
+		responses.add(GraphOperationMessage::Vector {
+			layer,
+			modification_type: VectorModificationType::InsertSegment {
+				id: segment_id,
+				points: [previous_id, next_id],
+				handles: [None, None],
+			},
+		});
+		Some(segment_id)
+	} else {
+		None
+	};
+
+	tool_data.points.remove(point_index);
+	// Remove the higher index first: removing `previous_segment` at `point_index - 1` first would shift
+	// `next_segment` down to `point_index - 1`, making the second `remove(point_index)` delete the wrong element.
+	if point_index < tool_data.segments.len() {
+		tool_data.segments.remove(point_index);
+	}
+	if let Some(index) = point_index.checked_sub(1) {
+		if index < tool_data.segments.len() {
+			tool_data.segments.remove(index);
+		}
+	}
+	if let Some(segment_id) = rejoined {
+		tool_data.segments.insert(point_index.saturating_sub(1).min(tool_data.segments.len()), (segment_id, [None, None]));
+	}
+}
+
+/// Draws the live rubber-band selection rectangle between the drag origin and the current cursor position.
+fn draw_box_select_overlay(document: &DocumentMessageHandler, layer: LayerNodeIdentifier, origin: DVec2, current: DVec2, overlay_context: &mut OverlayContext) {
+	let transform = document.metadata().transform_to_viewport(layer);
+	let origin_viewport = transform.transform_point2(origin);
+	let current_viewport = transform.transform_point2(current);
+
+	let corners = [
+		origin_viewport,
+		DVec2::new(current_viewport.x, origin_viewport.y),
+		current_viewport,
+		DVec2::new(origin_viewport.x, current_viewport.y),
+	];
+	for index in 0..corners.len() {
+		overlay_context.line(corners[index], corners[(index + 1) % corners.len()], None, None);
+	}
+}
+
+/// Highlights the currently box-selected points so the user can see what a delete or nudge will affect.
+fn draw_selection_overlays(document: &DocumentMessageHandler, layer: LayerNodeIdentifier, points: &[(PointId, DVec2, f64, Option<DVec2>)], selected_points: &[PointId], overlay_context: &mut OverlayContext) {
+	if selected_points.is_empty() {
+		return;
+	}
+
+	let transform = document.metadata().transform_to_viewport(layer);
+	for &(id, point_pos, ..) in points {
+		if selected_points.contains(&id) {
+			overlay_context.manipulator_handle(transform.transform_point2(point_pos), true, None);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn remove_spline_point_rejoins_neighbors_and_removes_both_segments() {
+		let mut tool_data = SplineToolData::default();
+		let (a, b, c) = (PointId::generate(), PointId::generate(), PointId::generate());
+		tool_data.points = vec![(a, DVec2::ZERO, 1., None), (b, DVec2::new(1., 0.), 1., None), (c, DVec2::new(2., 0.), 1., None)];
+		tool_data.segments = vec![(SegmentId::generate(), [None, None]), (SegmentId::generate(), [None, None])];
+		let layer = LayerNodeIdentifier::new_unchecked(NodeId(0));
+		let mut responses = VecDeque::new();
+
+		remove_spline_point(&mut tool_data, layer, b, &mut responses);
+
+		assert_eq!(tool_data.points.iter().map(|&(id, ..)| id).collect::<Vec<_>>(), vec![a, c]);
+		assert_eq!(tool_data.segments.len(), 1);
+		// Two `RemoveSegment`s, one `RemovePoint`, and one rejoining `InsertSegment`.
+		assert_eq!(responses.len(), 4);
+	}
+
+	#[test]
+	fn remove_spline_point_endpoint_removes_its_only_segment() {
+		let mut tool_data = SplineToolData::default();
+		let (a, b) = (PointId::generate(), PointId::generate());
+		tool_data.points = vec![(a, DVec2::ZERO, 1., None), (b, DVec2::new(1., 0.), 1., None)];
+		tool_data.segments = vec![(SegmentId::generate(), [None, None])];
+		let layer = LayerNodeIdentifier::new_unchecked(NodeId(0));
+		let mut responses = VecDeque::new();
+
+		remove_spline_point(&mut tool_data, layer, b, &mut responses);
+
+		assert_eq!(tool_data.points.iter().map(|&(id, ..)| id).collect::<Vec<_>>(), vec![a]);
+		assert!(tool_data.segments.is_empty());
+	}
+
+	#[test]
+	fn mirrored_positions_off_is_empty() {
+		assert!(mirrored_positions(SplineSymmetryMode::Off, DVec2::ZERO, DVec2::new(3., 4.)).is_empty());
+	}
+
+	#[test]
+	fn mirrored_positions_horizontal_reflects_across_origin_y() {
+		let mirrors = mirrored_positions(SplineSymmetryMode::Horizontal, DVec2::new(0., 10.), DVec2::new(3., 4.));
+		assert_eq!(mirrors, vec![DVec2::new(3., 16.)]);
+	}
+
+	#[test]
+	fn mirrored_positions_radial_produces_petals_minus_one_copies() {
+		let mirrors = mirrored_positions(SplineSymmetryMode::Radial(4), DVec2::ZERO, DVec2::new(1., 0.));
+		assert_eq!(mirrors.len(), 3);
+		assert!(mirrors[1].abs_diff_eq(DVec2::new(-1., 0.), 1e-10));
+	}
+
+	#[test]
+	fn thin_freehand_samples_empty_input_is_empty() {
+		assert!(thin_freehand_samples(&[]).is_empty());
+	}
+
+	#[test]
+	fn thin_freehand_samples_drops_points_within_drag_threshold() {
+		let samples = vec![(DVec2::ZERO, 1.), (DVec2::new(DRAG_THRESHOLD * 0.1, 0.), 1.), (DVec2::new(100., 0.), 1.)];
+		let thinned = thin_freehand_samples(&samples);
+		assert_eq!(thinned.len(), 2);
+		assert_eq!(thinned[0].0, DVec2::ZERO);
+		assert_eq!(thinned[1].0, DVec2::new(100., 0.));
+	}
+
+	#[test]
+	fn thin_freehand_samples_keeps_single_sample() {
+		let samples = vec![(DVec2::new(5., 5.), 1.)];
+		assert_eq!(thin_freehand_samples(&samples), samples);
+	}
+
+	#[test]
+	fn pull_stabilized_brush_holds_within_stabilizer_distance() {
+		let brush = DVec2::ZERO;
+		let cursor = DVec2::new(5., 0.);
+		assert_eq!(pull_stabilized_brush(brush, cursor, 10.), brush);
+	}
+
+	#[test]
+	fn pull_stabilized_brush_follows_once_past_stabilizer_distance() {
+		let brush = DVec2::ZERO;
+		let cursor = DVec2::new(20., 0.);
+		assert_eq!(pull_stabilized_brush(brush, cursor, 10.), DVec2::new(10., 0.));
+	}
+
+	#[test]
+	fn pull_stabilized_brush_ignores_stabilization_when_distance_is_zero() {
+		let brush = DVec2::ZERO;
+		let cursor = DVec2::new(20., 0.);
+		assert_eq!(pull_stabilized_brush(brush, cursor, 0.), cursor);
+	}
+}